@@ -1,5 +1,6 @@
 use pyo3::prelude::*;
 
+mod notation;
 mod shanten;
 
 /// Python에서 손 메트릭 평가
@@ -36,11 +37,11 @@ fn eval_hand_py(hand: Vec<u8>) -> PyResult<(i8, i8, i8, i8, (i8, i8, i8))> {
 /// Python에서 버림 후보 메트릭 평가
 ///
 /// Returns:
-///   List[ (tile_index, normal, chiitoi, kokushi, tanyao, (h_man,h_pin,h_sou)) ]
+///   List[ (tile_index, normal, chiitoi, kokushi, tanyao, (h_man,h_pin,h_sou), ukeire_kinds, ukeire_tiles) ]
 #[pyfunction]
 fn eval_discards_py(
     hand: Vec<u8>,
-) -> PyResult<Vec<(u8, i8, i8, i8, i8, (i8, i8, i8))>> {
+) -> PyResult<Vec<(u8, i8, i8, i8, i8, (i8, i8, i8), u8, u8)>> {
     if hand.len() != 34 {
         return Err(pyo3::exceptions::PyValueError::new_err(
             "hand must be length 34 (0..33 tile counts)",
@@ -67,15 +68,150 @@ fn eval_discards_py(
                 d.honitsu_distance[1],
                 d.honitsu_distance[2],
             ),
+            d.ukeire_kinds,
+            d.ukeire_tiles,
         ));
     }
 
     Ok(out)
 }
 
+/// Python에서 표준 표기법("123m456p789s1122z")을 타일 벡터로 파싱
+#[pyfunction]
+fn parse_hand_py(text: String) -> PyResult<Vec<u8>> {
+    let tiles = notation::parse_hand(&text)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    Ok(tiles.to_vec())
+}
+
+/// Python에서 타일 벡터를 표준 표기법 문자열로 변환
+#[pyfunction]
+fn format_hand_py(hand: Vec<u8>) -> PyResult<String> {
+    if hand.len() != 34 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "hand must be length 34 (0..33 tile counts)",
+        ));
+    }
+
+    let mut tiles = [0u8; 34];
+    for (i, &v) in hand.iter().enumerate() {
+        tiles[i] = v;
+    }
+
+    Ok(notation::to_text(&tiles))
+}
+
+/// Python에서 부르기(pon/chi/kan)가 있는 오픈 손 메트릭 평가
+///
+/// `concealed`는 손에 남은 타일만(부른 세트 제외) 담는다.
+///
+/// `melds > 0`일 때 chiitoi/kokushi/tanyao/honitsu는 적용 불가이므로
+/// i8::MAX(127)로 채워진다. 부른 세트가 있으면 치또이/고쿠시는 성립할 수
+/// 없고, 탄야오/혼일색은 어떤 패를 불렀는지(타일 식별 정보)가 필요하지만
+/// `melds`는 개수만 담고 있어 정확히 판정할 수 없기 때문이다.
+///
+/// Returns:
+///   (normal, chiitoi, kokushi, tanyao, (honitsu_man, honitsu_pin, honitsu_sou))
+#[pyfunction]
+fn eval_hand_open_py(
+    concealed: Vec<u8>,
+    melds: u8,
+) -> PyResult<(i8, i8, i8, i8, (i8, i8, i8))> {
+    if concealed.len() != 34 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "hand must be length 34 (0..33 tile counts)",
+        ));
+    }
+
+    let mut tiles = [0u8; 34];
+    for (i, &v) in concealed.iter().enumerate() {
+        tiles[i] = v;
+    }
+
+    let m = shanten::eval_hand_open(&tiles, melds);
+    Ok((
+        m.normal_shanten,
+        m.chiitoi_shanten,
+        m.kokushi_shanten,
+        m.tanyao_distance,
+        (
+            m.honitsu_distance[0],
+            m.honitsu_distance[1],
+            m.honitsu_distance[2],
+        ),
+    ))
+}
+
+/// Python에서 텐파이 손의 화료패(wait) 목록 계산
+#[pyfunction]
+fn waits_py(hand: Vec<u8>) -> PyResult<Vec<u8>> {
+    if hand.len() != 34 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "hand must be length 34 (0..33 tile counts)",
+        ));
+    }
+
+    let mut tiles = [0u8; 34];
+    for (i, &v) in hand.iter().enumerate() {
+        tiles[i] = v;
+    }
+
+    let count: u16 = tiles.iter().map(|&x| x as u16).sum();
+    let len_div3: u8 = (count / 3) as u8;
+
+    Ok(shanten::waits(&tiles, len_div3))
+}
+
+/// Python에서 다수의 손패를 한 번에 평가 (GIL을 풀고 rayon 풀에서 병렬 처리)
+///
+/// Returns:
+///   List[ (normal, chiitoi, kokushi, tanyao, (honitsu_man, honitsu_pin, honitsu_sou)) ]
+#[cfg(feature = "rayon")]
+#[pyfunction]
+fn eval_hands_batch_py(py: Python<'_>, hands: Vec<Vec<u8>>) -> PyResult<Vec<(i8, i8, i8, i8, (i8, i8, i8))>> {
+    let mut tiles_list = Vec::with_capacity(hands.len());
+    for hand in &hands {
+        if hand.len() != 34 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "hand must be length 34 (0..33 tile counts)",
+            ));
+        }
+        let mut tiles = [0u8; 34];
+        for (i, &v) in hand.iter().enumerate() {
+            tiles[i] = v;
+        }
+        tiles_list.push(tiles);
+    }
+
+    let metrics = py.allow_threads(|| shanten::par_eval_hands(&tiles_list));
+
+    Ok(metrics
+        .into_iter()
+        .map(|m| {
+            (
+                m.normal_shanten,
+                m.chiitoi_shanten,
+                m.kokushi_shanten,
+                m.tanyao_distance,
+                (
+                    m.honitsu_distance[0],
+                    m.honitsu_distance[1],
+                    m.honitsu_distance[2],
+                ),
+            )
+        })
+        .collect())
+}
+
 #[pymodule]
 fn shanten_pyo(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(eval_hand_py, m)?)?;
     m.add_function(wrap_pyfunction!(eval_discards_py, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_hand_py, m)?)?;
+    m.add_function(wrap_pyfunction!(format_hand_py, m)?)?;
+    m.add_function(wrap_pyfunction!(waits_py, m)?)?;
+    m.add_function(wrap_pyfunction!(eval_hand_open_py, m)?)?;
+    #[cfg(feature = "rayon")]
+    m.add_function(wrap_pyfunction!(eval_hands_batch_py, m)?)?;
     Ok(())
 }
\ No newline at end of file
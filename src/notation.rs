@@ -0,0 +1,159 @@
+//! Standard compact mahjong hand notation, e.g. `"123m456p789s1122z"`.
+//!
+//! Digits are grouped by a trailing suit suffix: `m`/`p`/`s` for man/pin/sou
+//! (digits 1-9) and `z` for the seven honors (digits 1-7, E/S/W/N/P/F/C).
+
+use std::fmt;
+
+/// Suit base index and digit range within the `[u8; 34]` tile layout.
+const SUITS: [(usize, u8, char); 4] = [(0, 9, 'm'), (9, 9, 'p'), (18, 9, 's'), (27, 7, 'z')];
+
+/// Error produced while parsing compact hand notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// A digit appeared before any suit suffix (`m`/`p`/`s`/`z`) closed the group,
+    /// or a suit suffix appeared with no digits before it.
+    MissingSuit,
+    /// A character that is neither a digit nor a recognized suit suffix.
+    InvalidChar(char),
+    /// Digit out of range for its suit (1-9 for m/p/s, 1-7 for z).
+    InvalidDigit { suit: char, digit: char },
+    /// Parsing this tile would exceed 4 copies.
+    TooManyCopies(u8),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingSuit => write!(f, "digit with no suit suffix (m/p/s/z), or suit suffix with no digits"),
+            ParseError::InvalidChar(c) => write!(f, "unexpected character '{c}'"),
+            ParseError::InvalidDigit { suit, digit } => {
+                write!(f, "digit '{digit}' is out of range for suit '{suit}'")
+            }
+            ParseError::TooManyCopies(idx) => {
+                write!(f, "tile index {idx} would exceed 4 copies")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse compact notation (e.g. `"123m456p789s1122z"`) into a `[u8; 34]` count vector.
+pub fn parse_hand(text: &str) -> Result<[u8; 34], ParseError> {
+    let mut tiles = [0u8; 34];
+    let mut pending: Vec<char> = Vec::new();
+
+    for c in text.chars() {
+        if c.is_ascii_digit() {
+            pending.push(c);
+            continue;
+        }
+
+        let Some(&(base, max_digit, _)) = SUITS.iter().find(|&&(_, _, suffix)| suffix == c) else {
+            return Err(ParseError::InvalidChar(c));
+        };
+
+        if pending.is_empty() {
+            return Err(ParseError::MissingSuit);
+        }
+
+        for &d in &pending {
+            let n = d.to_digit(10).unwrap() as u8;
+            if n == 0 || n > max_digit {
+                return Err(ParseError::InvalidDigit { suit: c, digit: d });
+            }
+
+            let idx = base + (n - 1) as usize;
+            tiles[idx] += 1;
+            if tiles[idx] > 4 {
+                return Err(ParseError::TooManyCopies(idx as u8));
+            }
+        }
+        pending.clear();
+    }
+
+    if !pending.is_empty() {
+        return Err(ParseError::MissingSuit);
+    }
+
+    Ok(tiles)
+}
+
+/// Format a `[u8; 34]` count vector as sorted canonical notation.
+pub fn to_text(tiles: &[u8; 34]) -> String {
+    let mut out = String::new();
+
+    for &(base, max_digit, suffix) in &SUITS {
+        let mut any = false;
+        for n in 1..=max_digit {
+            let idx = base + (n - 1) as usize;
+            for _ in 0..tiles[idx] {
+                out.push(char::from_digit(n as u32, 10).unwrap());
+                any = true;
+            }
+        }
+        if any {
+            out.push(suffix);
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_mixed_suits() {
+        let tiles = parse_hand("123m456p789s1122z").unwrap();
+        assert_eq!(tiles[0], 1); // 1m
+        assert_eq!(tiles[1], 1); // 2m
+        assert_eq!(tiles[2], 1); // 3m
+        assert_eq!(tiles[9 + 3], 1); // 4p
+        assert_eq!(tiles[18 + 6], 1); // 7s
+        assert_eq!(tiles[27], 2); // 1z
+        assert_eq!(tiles[28], 2); // 2z
+        assert_eq!(tiles.iter().map(|&c| c as u32).sum::<u32>(), 13);
+    }
+
+    #[test]
+    fn roundtrips_through_to_text() {
+        let text = "123m456p789s1122z";
+        let tiles = parse_hand(text).unwrap();
+        assert_eq!(to_text(&tiles), text);
+    }
+
+    #[test]
+    fn to_text_sorts_unordered_input() {
+        let tiles = parse_hand("321m").unwrap();
+        assert_eq!(to_text(&tiles), "123m");
+    }
+
+    #[test]
+    fn rejects_digit_without_suit_suffix() {
+        assert_eq!(parse_hand("123"), Err(ParseError::MissingSuit));
+    }
+
+    #[test]
+    fn rejects_out_of_range_honor_digit() {
+        assert_eq!(
+            parse_hand("8z"),
+            Err(ParseError::InvalidDigit {
+                suit: 'z',
+                digit: '8'
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_suffix() {
+        assert_eq!(parse_hand("1x"), Err(ParseError::InvalidChar('x')));
+    }
+
+    #[test]
+    fn rejects_more_than_four_copies() {
+        assert_eq!(parse_hand("11111m"), Err(ParseError::TooManyCopies(0)));
+    }
+}
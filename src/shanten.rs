@@ -138,6 +138,15 @@ pub fn calc_kokushi(tiles: &[u8; 34]) -> i8 {
     14 - kinds - redunct - 1
 }
 
+/// Normal-form shanten for an open hand holding `melds` already-completed calls.
+///
+/// Each meld is one already-finished set, so the concealed part only has to
+/// form the remaining `4 - melds` sets plus a pair.
+#[must_use]
+pub fn calc_normal_open(concealed: &[u8; 34], melds: u8) -> i8 {
+    calc_normal(concealed, 4 - melds.min(4))
+}
+
 #[must_use]
 pub fn calc_all(tiles: &[u8; 34], len_div3: u8) -> i8 {
     let mut shanten = calc_normal(tiles, len_div3);
@@ -165,11 +174,15 @@ pub struct HandMetrics {
     pub tanyao_distance: i8,
     /// [man, pin, sou]
     pub honitsu_distance: [i8; 3],
+    /// Number of accepting tile kinds (only meaningful for a 3k+1 shape, else 0)
+    pub ukeire_kinds: u8,
+    /// Total remaining tile count across accepting kinds (only meaningful for a 3k+1 shape, else 0)
+    pub ukeire_tiles: u8,
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct DiscardMetrics {
-    /// 0..33 (1m..9m, 1p..9p, 1s..9s, 자패 7종)
+    /// 0..33 (1m..9m, 1p..9p, 1s..9s, 7 honor kinds)
     pub tile_index: u8,
     pub normal_shanten: i8,
     pub chiitoi_shanten: i8,
@@ -177,6 +190,10 @@ pub struct DiscardMetrics {
     pub tanyao_distance: i8,
     /// [man, pin, sou]
     pub honitsu_distance: [i8; 3],
+    /// Number of accepting tile kinds after this discard
+    pub ukeire_kinds: u8,
+    /// Total remaining tile count across accepting kinds after this discard
+    pub ukeire_tiles: u8,
 }
 
 // ----- index helpers -----
@@ -301,6 +318,36 @@ pub fn honitsu_distance_for_suit(tiles: &[u8; 34], suit: u8) -> i8 {
     off_color as i8 + shanten_filtered
 }
 
+// ----- ukeire (tile acceptance) -----
+
+/// Finds the tiles that, if drawn, would lower the hand's shanten.
+///
+/// Only meaningful when `tiles` is in a 3k+1 shape (e.g. 13 tiles). Returns
+/// `(accepting tile indices, total remaining tile count across them)`.
+#[must_use]
+pub fn ukeire(tiles: &[u8; 34], len_div3: u8) -> (Vec<u8>, u32) {
+    let s = calc_all(tiles, len_div3);
+
+    let mut kinds = Vec::new();
+    let mut total: u32 = 0;
+
+    for i in 0..34 {
+        if tiles[i] >= 4 {
+            continue;
+        }
+
+        let mut tmp = *tiles;
+        tmp[i] += 1;
+
+        if calc_all(&tmp, len_div3) < s {
+            kinds.push(i as u8);
+            total += (4 - tiles[i]) as u32;
+        }
+    }
+
+    (kinds, total)
+}
+
 // ----- high-level eval -----
 
 #[must_use]
@@ -318,50 +365,696 @@ pub fn eval_hand(tiles: &[u8; 34]) -> HandMetrics {
         honitsu_distance_for_suit(tiles, 2),
     ];
 
+    let (ukeire_kinds, ukeire_tiles) = if count % 3 == 1 {
+        let (kinds, total) = ukeire(tiles, len_div3);
+        (kinds.len() as u8, total as u8)
+    } else {
+        (0, 0)
+    };
+
     HandMetrics {
         normal_shanten: normal,
         chiitoi_shanten: chiitoi,
         kokushi_shanten: kokushi,
         tanyao_distance: tanyao,
         honitsu_distance: honitsu,
+        ukeire_kinds,
+        ukeire_tiles,
+    }
+}
+
+/// Computes the metrics for the discard candidate that results from discarding tile `i`.
+fn eval_one_discard(tiles: &[u8; 34], i: usize) -> DiscardMetrics {
+    let mut tmp = *tiles;
+    tmp[i] -= 1;
+
+    let count: u16 = tmp.iter().map(|&x| x as u16).sum();
+    let len_div3: u8 = (count / 3) as u8;
+
+    let normal = calc_normal(&tmp, len_div3);
+    let chiitoi = calc_chitoi(&tmp);
+    let kokushi = calc_kokushi(&tmp);
+    let tanyao = tanyao_distance(&tmp);
+    let honitsu = [
+        honitsu_distance_for_suit(&tmp, 0),
+        honitsu_distance_for_suit(&tmp, 1),
+        honitsu_distance_for_suit(&tmp, 2),
+    ];
+    let (ukeire_kinds, total) = ukeire(&tmp, len_div3);
+
+    DiscardMetrics {
+        tile_index: i as u8,
+        normal_shanten: normal,
+        chiitoi_shanten: chiitoi,
+        kokushi_shanten: kokushi,
+        tanyao_distance: tanyao,
+        honitsu_distance: honitsu,
+        ukeire_kinds: ukeire_kinds.len() as u8,
+        ukeire_tiles: total as u8,
     }
 }
 
 #[must_use]
 pub fn eval_discards(tiles: &[u8; 34]) -> Vec<DiscardMetrics> {
+    (0..34)
+        .filter(|&i| tiles[i] > 0)
+        .map(|i| eval_one_discard(tiles, i))
+        .collect()
+}
+
+/// Parallel version of [`eval_discards`]. Spreads the 34 discard candidates across the rayon pool.
+#[cfg(feature = "rayon")]
+#[must_use]
+pub fn par_eval_discards(tiles: &[u8; 34]) -> Vec<DiscardMetrics> {
+    use rayon::prelude::*;
+
+    (0..34)
+        .into_par_iter()
+        .filter(|&i| tiles[i] > 0)
+        .map(|i| eval_one_discard(tiles, i))
+        .collect()
+}
+
+/// Evaluates many hands at once via [`eval_hand`] (spread across the rayon pool).
+#[cfg(feature = "rayon")]
+#[must_use]
+pub fn par_eval_hands(hands: &[[u8; 34]]) -> Vec<HandMetrics> {
+    use rayon::prelude::*;
+
+    hands.par_iter().map(eval_hand).collect()
+}
+
+// ----- waits / agari decomposition -----
+
+/// Winning tile (machi) indices that complete a tenpai hand.
+#[must_use]
+pub fn waits(tiles: &[u8; 34], len_div3: u8) -> Vec<u8> {
     let mut result = Vec::new();
 
     for i in 0..34 {
-        if tiles[i] == 0 {
+        if tiles[i] >= 4 {
             continue;
         }
 
-        // i 번 타일을 1장 버린다고 가정 → 새로운 손 구성
         let mut tmp = *tiles;
-        tmp[i] -= 1;
-
-        let count: u16 = tmp.iter().map(|&x| x as u16).sum();
-        let len_div3: u8 = (count / 3) as u8;
-
-        let normal = calc_normal(&tmp, len_div3);
-        let chiitoi = calc_chitoi(&tmp);
-        let kokushi = calc_kokushi(&tmp);
-        let tanyao = tanyao_distance(&tmp);
-        let honitsu = [
-            honitsu_distance_for_suit(&tmp, 0),
-            honitsu_distance_for_suit(&tmp, 1),
-            honitsu_distance_for_suit(&tmp, 2),
-        ];
-
-        result.push(DiscardMetrics {
-            tile_index: i as u8,
-            normal_shanten: normal,
-            chiitoi_shanten: chiitoi,
-            kokushi_shanten: kokushi,
-            tanyao_distance: tanyao,
-            honitsu_distance: honitsu,
-        });
+        tmp[i] += 1;
+
+        if calc_all(&tmp, len_div3) == -1 {
+            result.push(i as u8);
+        }
     }
 
     result
 }
+
+/// One set making up a complete (3k+2) hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Meld {
+    /// The pair. Tile index.
+    Pair(u8),
+    /// A sequence (e.g. 123). Start tile index.
+    Sequence(u8),
+    /// A triplet (three of the same tile). Tile index.
+    Triplet(u8),
+}
+
+/// Decomposes a complete 14-tile standard-form hand into 4 sets + 1 pair.
+///
+/// Only covers the standard shape; `None` means no such decomposition
+/// exists, which is also what a kokushi-musou win, and a chiitoitsu win
+/// whose pairs don't also split into sets, produces (14 tiles, but no
+/// 4-sets-plus-pair split). Callers must check for those hand types
+/// themselves before treating `None` as "not a win".
+#[must_use]
+pub fn decompose_agari(tiles: &[u8; 34]) -> Option<Vec<Meld>> {
+    let count: u16 = tiles.iter().map(|&x| x as u16).sum();
+    if count != 14 {
+        return None;
+    }
+
+    for i in 0..34 {
+        if tiles[i] < 2 {
+            continue;
+        }
+
+        let mut rest = *tiles;
+        rest[i] -= 2;
+
+        let mut melds = vec![Meld::Pair(i as u8)];
+        if decompose_sets(&mut rest, &mut melds) {
+            return Some(melds);
+        }
+    }
+
+    None
+}
+
+// ----- open hand (fuuro) eval -----
+
+/// Evaluates an open hand that has already declared `melds` sets via pon/chi/kan.
+///
+/// `concealed` holds only the tiles still in hand (not called): `13 - 3*melds`
+/// tiles, +1 when holding a drawn tile. Chiitoi/kokushi require a closed hand,
+/// so they're marked `i8::MAX` (impossible) whenever `melds > 0`. Tanyao/honitsu
+/// also depend on which tiles were called (e.g. a `999m` pon rules out tanyao,
+/// an off-suit pon rules out honitsu), but `melds` only carries a count, not
+/// tile identities, so they too are marked `i8::MAX` for `melds > 0` rather
+/// than silently reporting an optimistic distance. ukeire is not computed
+/// here and is left (0, 0) for every `melds`, including 0.
+#[must_use]
+pub fn eval_hand_open(concealed: &[u8; 34], melds: u8) -> HandMetrics {
+    let normal = calc_normal_open(concealed, melds);
+    let (chiitoi, kokushi) = if melds == 0 {
+        (calc_chitoi(concealed), calc_kokushi(concealed))
+    } else {
+        (i8::MAX, i8::MAX)
+    };
+    let (tanyao, honitsu) = if melds == 0 {
+        (
+            tanyao_distance(concealed),
+            [
+                honitsu_distance_for_suit(concealed, 0),
+                honitsu_distance_for_suit(concealed, 1),
+                honitsu_distance_for_suit(concealed, 2),
+            ],
+        )
+    } else {
+        (i8::MAX, [i8::MAX; 3])
+    };
+
+    HandMetrics {
+        normal_shanten: normal,
+        chiitoi_shanten: chiitoi,
+        kokushi_shanten: kokushi,
+        tanyao_distance: tanyao,
+        honitsu_distance: honitsu,
+        ukeire_kinds: 0,
+        ukeire_tiles: 0,
+    }
+}
+
+/// Computes the metrics for the open-hand discard candidate that results from discarding tile `i`.
+fn eval_one_discard_open(concealed: &[u8; 34], i: usize, melds: u8) -> DiscardMetrics {
+    let mut tmp = *concealed;
+    tmp[i] -= 1;
+
+    let normal = calc_normal_open(&tmp, melds);
+    let (chiitoi, kokushi) = if melds == 0 {
+        (calc_chitoi(&tmp), calc_kokushi(&tmp))
+    } else {
+        (i8::MAX, i8::MAX)
+    };
+    let (tanyao, honitsu) = if melds == 0 {
+        (
+            tanyao_distance(&tmp),
+            [
+                honitsu_distance_for_suit(&tmp, 0),
+                honitsu_distance_for_suit(&tmp, 1),
+                honitsu_distance_for_suit(&tmp, 2),
+            ],
+        )
+    } else {
+        (i8::MAX, [i8::MAX; 3])
+    };
+
+    DiscardMetrics {
+        tile_index: i as u8,
+        normal_shanten: normal,
+        chiitoi_shanten: chiitoi,
+        kokushi_shanten: kokushi,
+        tanyao_distance: tanyao,
+        honitsu_distance: honitsu,
+        ukeire_kinds: 0,
+        ukeire_tiles: 0,
+    }
+}
+
+/// Open-hand version of [`eval_discards`]. Iterates only over tiles discardable from `concealed`.
+/// ukeire is not computed here: `ukeire_kinds` and `ukeire_tiles` are always 0.
+#[must_use]
+pub fn eval_discards_open(concealed: &[u8; 34], melds: u8) -> Vec<DiscardMetrics> {
+    (0..34)
+        .filter(|&i| concealed[i] > 0)
+        .map(|i| eval_one_discard_open(concealed, i, melds))
+        .collect()
+}
+
+/// Greedily peels a triplet or sequence off the lowest remaining tile, backtracking on failure.
+fn decompose_sets(tiles: &mut [u8; 34], melds: &mut Vec<Meld>) -> bool {
+    let Some(i) = (0..34).find(|&i| tiles[i] > 0) else {
+        return true;
+    };
+
+    if tiles[i] >= 3 {
+        tiles[i] -= 3;
+        melds.push(Meld::Triplet(i as u8));
+        if decompose_sets(tiles, melds) {
+            return true;
+        }
+        melds.pop();
+        tiles[i] += 3;
+    }
+
+    // Honor tiles can't form sequences.
+    if i < 27 && i % 9 <= 6 && tiles[i + 1] > 0 && tiles[i + 2] > 0 {
+        tiles[i] -= 1;
+        tiles[i + 1] -= 1;
+        tiles[i + 2] -= 1;
+        melds.push(Meld::Sequence(i as u8));
+        if decompose_sets(tiles, melds) {
+            return true;
+        }
+        melds.pop();
+        tiles[i] += 1;
+        tiles[i + 1] += 1;
+        tiles[i + 2] += 1;
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tiles_from(entries: &[(usize, u8)]) -> [u8; 34] {
+        let mut tiles = [0u8; 34];
+        for &(i, c) in entries {
+            tiles[i] = c;
+        }
+        tiles
+    }
+
+    #[test]
+    fn ukeire_finds_the_single_tile_that_completes_a_tanki_wait() {
+        // 123m 456m 789m 123p 1s (13 tiles): four complete sets plus a
+        // lone 1s waiting for its pair (tanki wait). Drawing 1s is the
+        // only way to finish, so it's the sole accepting tile and all 3
+        // remaining copies count toward the total.
+        let tiles = tiles_from(&[
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (4, 1),
+            (5, 1),
+            (6, 1),
+            (7, 1),
+            (8, 1),
+            (9, 1),
+            (10, 1),
+            (11, 1),
+            (18, 1),
+        ]);
+        let (kinds, total) = ukeire(&tiles, 4);
+        assert_eq!(kinds, vec![18]);
+        assert_eq!(total, 3);
+    }
+
+    #[test]
+    fn ukeire_finds_both_sides_of_a_two_sided_wait() {
+        // 123m 456m 789m 11p 45s (13 tiles): three complete runs, a pair
+        // (11p), and a 45s ryanmen waiting on 3s or 6s to complete the
+        // fourth set.
+        let tiles = tiles_from(&[
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (4, 1),
+            (5, 1),
+            (6, 1),
+            (7, 1),
+            (8, 1),
+            (9, 2),
+            (21, 1),
+            (22, 1),
+        ]);
+        let (mut kinds, total) = ukeire(&tiles, 4);
+        kinds.sort_unstable();
+        assert_eq!(kinds, vec![20, 23]); // 3s, 6s
+        assert_eq!(total, 8); // 4 copies of 3s + 4 copies of 6s
+    }
+
+    #[test]
+    fn waits_finds_the_single_winning_tile_of_a_tanki_wait() {
+        // Same shape as the tanki-wait ukeire case: four complete sets
+        // plus a lone 1s waiting for its pair. The only winning tile is 1s.
+        let tiles = tiles_from(&[
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (4, 1),
+            (5, 1),
+            (6, 1),
+            (7, 1),
+            (8, 1),
+            (9, 1),
+            (10, 1),
+            (11, 1),
+            (18, 1),
+        ]);
+
+        assert_eq!(waits(&tiles, 4), vec![18]);
+    }
+
+    #[test]
+    fn waits_finds_both_sides_of_a_two_sided_wait() {
+        // 123m 456m 789m 11p 45s: same shape as the ryanmen ukeire case,
+        // winning on either 3s or 6s.
+        let tiles = tiles_from(&[
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (4, 1),
+            (5, 1),
+            (6, 1),
+            (7, 1),
+            (8, 1),
+            (9, 2),
+            (21, 1),
+            (22, 1),
+        ]);
+
+        assert_eq!(waits(&tiles, 4), vec![20, 23]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_eval_discards_agrees_with_the_serial_path() {
+        let tiles = tiles_from(&[
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (4, 1),
+            (5, 1),
+            (6, 1),
+            (7, 1),
+            (8, 1),
+            (9, 1),
+            (10, 1),
+            (11, 1),
+            (18, 1),
+        ]);
+
+        let mut serial = eval_discards(&tiles);
+        let mut parallel = par_eval_discards(&tiles);
+        serial.sort_by_key(|d| d.tile_index);
+        parallel.sort_by_key(|d| d.tile_index);
+
+        assert_eq!(
+            serial.iter().map(|d| d.tile_index).collect::<Vec<_>>(),
+            parallel.iter().map(|d| d.tile_index).collect::<Vec<_>>()
+        );
+        for (s, p) in serial.iter().zip(parallel.iter()) {
+            assert_eq!(s.normal_shanten, p.normal_shanten);
+            assert_eq!(s.chiitoi_shanten, p.chiitoi_shanten);
+            assert_eq!(s.kokushi_shanten, p.kokushi_shanten);
+            assert_eq!(s.tanyao_distance, p.tanyao_distance);
+            assert_eq!(s.honitsu_distance, p.honitsu_distance);
+            assert_eq!(s.ukeire_kinds, p.ukeire_kinds);
+            assert_eq!(s.ukeire_tiles, p.ukeire_tiles);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_eval_hands_agrees_with_the_serial_path() {
+        let hand_a = tiles_from(&[
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (4, 1),
+            (5, 1),
+            (6, 1),
+            (7, 1),
+            (8, 1),
+            (9, 1),
+            (10, 1),
+            (11, 1),
+            (18, 1),
+        ]);
+        let hand_b = tiles_from(&[(9, 1), (10, 1), (18, 2)]);
+        let hands = vec![hand_a, hand_b];
+
+        let serial: Vec<_> = hands.iter().map(eval_hand).collect();
+        let parallel = par_eval_hands(&hands);
+
+        assert_eq!(serial.len(), parallel.len());
+        for (s, p) in serial.iter().zip(parallel.iter()) {
+            assert_eq!(s.normal_shanten, p.normal_shanten);
+            assert_eq!(s.chiitoi_shanten, p.chiitoi_shanten);
+            assert_eq!(s.kokushi_shanten, p.kokushi_shanten);
+            assert_eq!(s.tanyao_distance, p.tanyao_distance);
+            assert_eq!(s.honitsu_distance, p.honitsu_distance);
+            assert_eq!(s.ukeire_kinds, p.ukeire_kinds);
+            assert_eq!(s.ukeire_tiles, p.ukeire_tiles);
+        }
+    }
+
+    #[test]
+    fn calc_normal_open_matches_closed_shanten_at_zero_melds() {
+        // 123m 456m 789m 12p 11s (13 tiles, concealed only): three
+        // complete sets, a 12p partial run, and an 11s pair. Tenpai,
+        // waiting on 3p; with no melds this must equal the closed-hand
+        // shanten exactly.
+        let concealed = tiles_from(&[
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (4, 1),
+            (5, 1),
+            (6, 1),
+            (7, 1),
+            (8, 1),
+            (9, 1),
+            (10, 1),
+            (18, 2),
+        ]);
+
+        assert_eq!(calc_normal_open(&concealed, 0), calc_normal(&concealed, 4));
+        assert_eq!(calc_normal_open(&concealed, 0), 0);
+    }
+
+    #[test]
+    fn calc_normal_open_one_meld_matches_the_equivalent_closed_tenpai_hand() {
+        // Same tenpai hand as above, but with 789m already called as a
+        // pon: concealed loses that set (10 tiles: 123m 456m 12p 11s) and
+        // melds = 1. Calling a complete set out doesn't change the overall
+        // shanten, so this must still come out tenpai (0).
+        let concealed = tiles_from(&[
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (4, 1),
+            (5, 1),
+            (9, 1),
+            (10, 1),
+            (18, 2),
+        ]);
+
+        assert_eq!(calc_normal_open(&concealed, 1), 0);
+    }
+
+    #[test]
+    fn calc_normal_open_two_melds_matches_the_equivalent_closed_tenpai_hand() {
+        // 123m 12p 11s (7 tiles) with melds = 2 (456m and 789m called):
+        // still the same tenpai hand overall, still shanten 0.
+        let concealed = tiles_from(&[(0, 1), (1, 1), (2, 1), (9, 1), (10, 1), (18, 2)]);
+
+        assert_eq!(calc_normal_open(&concealed, 2), 0);
+    }
+
+    #[test]
+    fn calc_normal_open_three_melds_matches_the_equivalent_closed_tenpai_hand() {
+        // 12p 11s (4 tiles) with melds = 3 (123m, 456m, 789m all called):
+        // still the same tenpai hand overall, still shanten 0.
+        let concealed = tiles_from(&[(9, 1), (10, 1), (18, 2)]);
+
+        assert_eq!(calc_normal_open(&concealed, 3), 0);
+    }
+
+    #[test]
+    fn calc_normal_open_one_meld_matches_the_equivalent_closed_complete_hand() {
+        // 123m 456m 789m 11p (11 tiles, concealed) + 1 meld (e.g. 123s
+        // called): a complete, already-won hand, so shanten = -1.
+        let concealed = tiles_from(&[
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (4, 1),
+            (5, 1),
+            (6, 1),
+            (7, 1),
+            (8, 1),
+            (9, 2),
+        ]);
+
+        assert_eq!(calc_normal_open(&concealed, 1), -1);
+    }
+
+    #[test]
+    fn eval_hand_open_disables_chiitoi_kokushi_tanyao_honitsu_when_melds_positive() {
+        let concealed = tiles_from(&[(9, 1), (10, 1), (18, 2)]);
+        let m = eval_hand_open(&concealed, 3);
+
+        assert_eq!(m.normal_shanten, 0);
+        assert_eq!(m.chiitoi_shanten, i8::MAX);
+        assert_eq!(m.kokushi_shanten, i8::MAX);
+        assert_eq!(m.tanyao_distance, i8::MAX);
+        assert_eq!(m.honitsu_distance, [i8::MAX; 3]);
+    }
+
+    #[test]
+    fn eval_hand_open_computes_chiitoi_kokushi_tanyao_honitsu_when_closed() {
+        let concealed = tiles_from(&[
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (3, 1),
+            (4, 1),
+            (5, 1),
+            (6, 1),
+            (7, 1),
+            (8, 1),
+            (9, 1),
+            (10, 1),
+            (18, 2),
+        ]);
+        let m = eval_hand_open(&concealed, 0);
+
+        assert_eq!(m.normal_shanten, 0);
+        assert_eq!(m.chiitoi_shanten, calc_chitoi(&concealed));
+        assert_eq!(m.kokushi_shanten, calc_kokushi(&concealed));
+        assert_eq!(m.tanyao_distance, tanyao_distance(&concealed));
+        assert_ne!(m.tanyao_distance, i8::MAX);
+        assert_eq!(
+            m.honitsu_distance,
+            [
+                honitsu_distance_for_suit(&concealed, 0),
+                honitsu_distance_for_suit(&concealed, 1),
+                honitsu_distance_for_suit(&concealed, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn eval_discards_open_matches_calc_normal_open_per_discard() {
+        // Concealed 12p 11s + 1z (5 tiles) with melds = 3: discarding 1z
+        // should land back on the same tenpai hand tested above.
+        let concealed = tiles_from(&[(9, 1), (10, 1), (18, 2), (27, 1)]);
+
+        let discards = eval_discards_open(&concealed, 3);
+        let discard_1z = discards
+            .iter()
+            .find(|d| d.tile_index == 27)
+            .expect("1z should be a discard candidate");
+
+        assert_eq!(discard_1z.normal_shanten, 0);
+        assert_eq!(discard_1z.chiitoi_shanten, i8::MAX);
+        assert_eq!(discard_1z.kokushi_shanten, i8::MAX);
+    }
+
+    #[test]
+    fn decompose_agari_rejects_wrong_tile_count() {
+        // 13 tiles: not a complete (14-tile) hand.
+        let tiles = tiles_from(&[
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (9, 1),
+            (10, 1),
+            (11, 1),
+            (18, 1),
+            (19, 1),
+            (20, 1),
+            (21, 1),
+            (22, 1),
+            (23, 1),
+            (27, 1),
+        ]);
+        assert_eq!(tiles.iter().map(|&c| c as u32).sum::<u32>(), 13);
+        assert_eq!(decompose_agari(&tiles), None);
+    }
+
+    #[test]
+    fn decompose_agari_returns_none_when_no_pair_candidate_exists() {
+        // 14 distinct single tiles: nothing has a second copy to anchor the pair.
+        let entries: Vec<(usize, u8)> = (0..14).map(|i| (i, 1)).collect();
+        let tiles = tiles_from(&entries);
+        assert_eq!(decompose_agari(&tiles), None);
+    }
+
+    #[test]
+    fn decompose_agari_finds_straightforward_sequences() {
+        // 123m 123p 123s 456s 11z
+        let tiles = tiles_from(&[
+            (0, 1),
+            (1, 1),
+            (2, 1),
+            (9, 1),
+            (10, 1),
+            (11, 1),
+            (18, 1),
+            (19, 1),
+            (20, 1),
+            (21, 1),
+            (22, 1),
+            (23, 1),
+            (27, 2),
+        ]);
+
+        let melds = decompose_agari(&tiles).expect("should decompose");
+        assert_eq!(melds.len(), 5);
+        assert!(melds.contains(&Meld::Pair(27)));
+        assert!(melds.contains(&Meld::Sequence(0)));
+        assert!(melds.contains(&Meld::Sequence(9)));
+        assert!(melds.contains(&Meld::Sequence(18)));
+        assert!(melds.contains(&Meld::Sequence(21)));
+    }
+
+    #[test]
+    fn decompose_agari_backtracks_past_a_dead_end_pair_candidate() {
+        // Man suit: 1111 23 -> 4 copies of 1m plus one 2m and one 3m.
+        // Pin: 123p, Sou: 123s, honors: 11z.
+        //
+        // The first pair candidate scanned is the 1m block (it has >= 2
+        // copies), but picking it as the pair strands the honor pair (11z)
+        // with no set to belong to, so that branch must fail and the search
+        // has to move on to the real pair (11z). Once it does, the leftover
+        // man tiles (1,1,1,1,2,3) resolve as a triplet of 1m followed by a
+        // 123m sequence, exercising both the triplet and sequence arms of
+        // decompose_sets within a single decomposition.
+        let tiles = tiles_from(&[
+            (0, 4),
+            (1, 1),
+            (2, 1),
+            (9, 1),
+            (10, 1),
+            (11, 1),
+            (18, 1),
+            (19, 1),
+            (20, 1),
+            (27, 2),
+        ]);
+
+        let melds = decompose_agari(&tiles).expect("should decompose");
+        assert_eq!(
+            melds,
+            vec![
+                Meld::Pair(27),
+                Meld::Triplet(0),
+                Meld::Sequence(0),
+                Meld::Sequence(9),
+                Meld::Sequence(18),
+            ]
+        );
+    }
+}